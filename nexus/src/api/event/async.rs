@@ -0,0 +1,230 @@
+//! Async bridge for events, gated behind the `async` feature.
+//!
+//! The event callbacks registered through [`subscribe`](super::Event::subscribe) or
+//! [`subscribe_fn`](super::Event::subscribe_fn) only ever receive a payload pointer that is valid
+//! for the duration of the call, and the host never hands addons a thread of their own to block
+//! on. So instead of exposing a blocking `block_on`, this module mirrors the
+//! dispatcher/executor/event-loop split used by Firefox's `moz_task`: [`spawn_local`] enqueues a
+//! future, and [`pump`] (wired up to an existing render or [`wnd_proc`](crate::wnd_proc)
+//! callback) polls every future spawned on the same thread that is currently ready to make
+//! progress.
+//!
+//! [`Event::recv`](super::Event::recv) and [`Event::stream`](super::Event::stream) are built on
+//! top of [`subscribe_fn`](super::Event::subscribe_fn): the subscription callback fires on
+//! whatever thread the host raises the event from, while the future or stream is polled on
+//! whichever thread calls [`pump`]. Since those are not guaranteed to be the same thread, the
+//! subscription copies each raised payload into a small `Send` mpsc-style channel rather than
+//! sharing it directly, which is why the payload type must be [`Clone`] and [`Send`].
+
+use super::{trampoline::SlotsExhausted, Event};
+use crate::revertible::Revertible;
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Wake, Waker},
+};
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+struct Task {
+    future: Mutex<Option<LocalFuture>>,
+    queue: Arc<Mutex<VecDeque<Arc<Task>>>>,
+}
+
+// SAFETY: a `Task` is only ever polled from the single thread that calls `pump`. `Send + Sync`
+// is only needed so the task can be re-queued from a `Waker`, which the host may invoke from any
+// thread; waking merely pushes the `Arc<Task>` onto a `Mutex`-guarded queue and never touches the
+// future itself from the waking thread.
+unsafe impl Send for Task {}
+unsafe impl Sync for Task {}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        Self::wake_by_ref(&self)
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.queue
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push_back(self.clone());
+    }
+}
+
+thread_local! {
+    static QUEUE: Arc<Mutex<VecDeque<Arc<Task>>>> = Arc::new(Mutex::new(VecDeque::new()));
+}
+
+/// Spawns a future onto the calling thread's local executor.
+///
+/// The future is polled the next time [`pump`] runs on this thread, and again whenever it wakes itself in the
+/// meantime. It never makes progress unless something calls [`pump`] regularly.
+pub fn spawn_local(future: impl Future<Output = ()> + 'static) {
+    QUEUE.with(|queue| {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            queue: queue.clone(),
+        });
+        queue
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push_back(task);
+    });
+}
+
+/// Polls every future spawned on the calling thread that is currently ready to make progress.
+///
+/// The task queue is per thread: futures passed to [`spawn_local`] on another thread are not
+/// polled here.
+///
+/// Call this once per frame from an existing render or [`wnd_proc`](crate::wnd_proc) callback so
+/// futures built on [`Event::recv`] and [`Event::stream`] actually make progress.
+pub fn pump() {
+    let ready: Vec<_> = QUEUE.with(|queue| {
+        queue
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .drain(..)
+            .collect()
+    });
+
+    for task in ready {
+        let mut slot = task.future.lock().unwrap_or_else(|err| err.into_inner());
+        let Some(mut future) = slot.take() else {
+            continue;
+        };
+        drop(slot);
+
+        let waker = Waker::from(task.clone());
+        let mut cx = Context::from_waker(&waker);
+        if future.as_mut().poll(&mut cx).is_pending() {
+            *task.future.lock().unwrap_or_else(|err| err.into_inner()) = Some(future);
+        }
+    }
+}
+
+/// Small `Send` mpsc-style channel carrying payloads copied out of an event subscription, plus
+/// the waker of whoever is currently waiting on it.
+///
+/// Unlike [`std::sync::mpsc`], receiving does not block; [`Inbox::poll_pop`] registers the
+/// current waker and returns [`Poll::Pending`] instead.
+struct Inbox<T> {
+    queue: Mutex<VecDeque<T>>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl<T> Inbox<T> {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            queue: Mutex::new(VecDeque::new()),
+            waker: Mutex::new(None),
+        })
+    }
+
+    fn push(&self, value: T) {
+        self.queue
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push_back(value);
+        if let Some(waker) = self.waker.lock().unwrap_or_else(|err| err.into_inner()).take() {
+            waker.wake();
+        }
+    }
+
+    fn poll_pop(&self, cx: &Context<'_>) -> Poll<T> {
+        match self.queue.lock().unwrap_or_else(|err| err.into_inner()).pop_front() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                *self.waker.lock().unwrap_or_else(|err| err.into_inner()) = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Future returned by [`Event::recv`], resolving with the event's payload on its next raise.
+///
+/// Dropping the future before it resolves unsubscribes the underlying closure.
+pub struct EventRecv<T, F: Fn() + Send + Sync + Clone + 'static> {
+    inbox: Arc<Inbox<T>>,
+    subscription: Revertible<F>,
+}
+
+/// Creates the [`EventRecv`] for [`Event::recv`].
+///
+/// A free function rather than an inherent `EventRecv::new`, since naming the closure type
+/// `subscribe_fn` returns would otherwise require spelling `impl Trait` in an impl header, which
+/// Rust does not allow; returning it in this function's return type is fine.
+///
+/// # Errors
+/// Returns [`SlotsExhausted`] if every closure slot is currently in use.
+pub(super) fn new_recv<T: Clone + Send + 'static>(
+    event: &Event<T>,
+) -> Result<EventRecv<T, impl Fn() + Send + Sync + Clone + 'static>, SlotsExhausted> {
+    let inbox = Inbox::new();
+    let subscribed = inbox.clone();
+    let subscription = event.subscribe_fn(move |payload| {
+        if let Some(payload) = payload {
+            subscribed.push(payload.clone());
+        }
+    })?;
+    Ok(EventRecv { inbox, subscription })
+}
+
+impl<T, F: Fn() + Send + Sync + Clone + 'static> Future for EventRecv<T, F> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inbox.poll_pop(cx)
+    }
+}
+
+impl<T, F: Fn() + Send + Sync + Clone + 'static> Drop for EventRecv<T, F> {
+    fn drop(&mut self) {
+        self.subscription.clone().revert();
+    }
+}
+
+/// [`Stream`](futures::Stream) returned by [`Event::stream`], yielding the event's payload on
+/// every raise.
+///
+/// Dropping the stream unsubscribes the underlying closure.
+pub struct EventStream<T, F: Fn() + Send + Sync + Clone + 'static> {
+    inbox: Arc<Inbox<T>>,
+    subscription: Revertible<F>,
+}
+
+/// Creates the [`EventStream`] for [`Event::stream`].
+///
+/// See [`new_recv`] for why this is a free function rather than an inherent `EventStream::new`.
+///
+/// # Errors
+/// Returns [`SlotsExhausted`] if every closure slot is currently in use.
+pub(super) fn new_stream<T: Clone + Send + 'static>(
+    event: &Event<T>,
+) -> Result<EventStream<T, impl Fn() + Send + Sync + Clone + 'static>, SlotsExhausted> {
+    let inbox = Inbox::new();
+    let subscribed = inbox.clone();
+    let subscription = event.subscribe_fn(move |payload| {
+        if let Some(payload) = payload {
+            subscribed.push(payload.clone());
+        }
+    })?;
+    Ok(EventStream { inbox, subscription })
+}
+
+impl<T, F: Fn() + Send + Sync + Clone + 'static> futures::Stream for EventStream<T, F> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inbox.poll_pop(cx).map(Some)
+    }
+}
+
+impl<T, F: Fn() + Send + Sync + Clone + 'static> Drop for EventStream<T, F> {
+    fn drop(&mut self) {
+        self.subscription.clone().revert();
+    }
+}
@@ -0,0 +1,200 @@
+//! Priority-ordered, cancelable local event bus.
+//!
+//! [`subscribe`](super::Event::subscribe) and [`subscribe_fn`](super::Event::subscribe_fn) each
+//! register their own callback with the host, so several handlers for the same identifier run in
+//! whatever order the host happens to call them in, and none of them can stop the others from
+//! running. This module instead registers exactly one managed trampoline per identifier (reusing
+//! [`event_subscribe_closure`](super::event_subscribe_closure)) and fans each raise out to a
+//! locally-sorted list of handlers, highest [`priority`](event_subscribe_prioritized) first. A
+//! handler returning [`Propagation::Stop`] prevents lower-priority handlers from seeing that
+//! raise. The managed trampoline is kept alive only for as long as at least one local handler is
+//! registered for its identifier.
+
+use super::{event_subscribe_closure, trampoline::SlotsExhausted, Event};
+use crate::revertible::Revertible;
+use std::{
+    any::Any,
+    collections::{hash_map::Entry as MapEntry, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+/// Whether an event bus handler lets lower-priority handlers run or stops the raise there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Propagation {
+    /// Let lower-priority handlers run for this raise.
+    Continue,
+    /// Prevent lower-priority handlers from running for this raise.
+    Stop,
+}
+
+type BusHandler<T> = Box<dyn FnMut(Option<&T>) -> Propagation + Send>;
+
+struct Entry<T> {
+    id: u64,
+    priority: i32,
+    /// `None` while [`dispatch`] is calling this entry's handler; see [`dispatch`] for why it is
+    /// taken out rather than called behind the registry lock.
+    handler: Option<BusHandler<T>>,
+}
+
+struct Bus<T> {
+    handlers: Vec<Entry<T>>,
+    /// Reverts the one managed host subscription backing this bus. Taken and called once the
+    /// last handler is removed.
+    teardown: Option<Box<dyn FnOnce() + Send>>,
+}
+
+impl<T: 'static> Bus<T> {
+    fn new(identifier: &'static str) -> Result<Self, SlotsExhausted> {
+        let subscription = unsafe {
+            event_subscribe_closure::<T>(identifier, move |payload| dispatch::<T>(identifier, payload))
+        }?;
+        Ok(Self {
+            handlers: Vec::new(),
+            teardown: Some(Box::new(move || subscription.revert())),
+        })
+    }
+
+    fn insert(&mut self, id: u64, priority: i32, handler: BusHandler<T>) {
+        self.handlers.push(Entry {
+            id,
+            priority,
+            handler: Some(handler),
+        });
+        self.handlers.sort_by(|a, b| b.priority.cmp(&a.priority));
+    }
+
+    fn entry_mut(&mut self, id: u64) -> Option<&mut Entry<T>> {
+        self.handlers.iter_mut().find(|entry| entry.id == id)
+    }
+}
+
+/// Map from event identifier to its type-erased [`Bus`].
+///
+/// A single registry is shared across all payload types; each [`Bus<T>`] is stored behind
+/// `dyn Any` and downcast back using the identifier's known payload type.
+static BUSES: OnceLock<Mutex<HashMap<&'static str, Box<dyn Any + Send>>>> = OnceLock::new();
+
+fn buses() -> &'static Mutex<HashMap<&'static str, Box<dyn Any + Send>>> {
+    BUSES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Fans a raise out to every local handler registered for `identifier`, highest priority first.
+///
+/// The registry lock is only ever held to snapshot the handler ids, to take a single handler out
+/// of its slot, or to put it back, never while the handler itself is running. That is what lets a handler subscribe,
+/// unsubscribe (including unsubscribing itself to "consume" the event), or raise reentrantly
+/// without deadlocking on the non-reentrant `BUSES` mutex.
+fn dispatch<T: 'static>(identifier: &'static str, payload: Option<&T>) {
+    let ids: Vec<u64> = {
+        let mut buses = buses().lock().unwrap_or_else(|err| err.into_inner());
+        let Some(bus) = buses
+            .get_mut(identifier)
+            .and_then(|bus| bus.downcast_mut::<Bus<T>>())
+        else {
+            return;
+        };
+        bus.handlers.iter().map(|entry| entry.id).collect()
+    };
+
+    for id in ids {
+        let taken = {
+            let mut buses = buses().lock().unwrap_or_else(|err| err.into_inner());
+            buses
+                .get_mut(identifier)
+                .and_then(|bus| bus.downcast_mut::<Bus<T>>())
+                .and_then(|bus| bus.entry_mut(id))
+                .and_then(|entry| entry.handler.take())
+        };
+        let Some(mut handler) = taken else {
+            // Already removed, or another in-flight dispatch is currently calling it.
+            continue;
+        };
+
+        let propagation = handler(payload);
+
+        let mut buses = buses().lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(entry) = buses
+            .get_mut(identifier)
+            .and_then(|bus| bus.downcast_mut::<Bus<T>>())
+            .and_then(|bus| bus.entry_mut(id))
+        {
+            entry.handler = Some(handler);
+        }
+        drop(buses);
+
+        if propagation == Propagation::Stop {
+            break;
+        }
+    }
+}
+
+/// Subscribes a priority-ordered, cancelable handler to the event bus for the given identifier.
+///
+/// Handlers for the same identifier run highest priority first; a handler returning
+/// [`Propagation::Stop`] prevents lower-priority handlers from running for that raise.
+///
+/// Returns a [`Revertible`] that removes just this handler. The single managed host subscription
+/// for the identifier is kept alive until the last of its local handlers is removed.
+///
+/// # Errors
+/// Returns [`SlotsExhausted`] if a managed trampoline still needs to be claimed for this
+/// identifier and every closure slot is currently in use.
+pub fn event_subscribe_prioritized<T: 'static>(
+    identifier: &'static str,
+    priority: i32,
+    handler: impl FnMut(Option<&T>) -> Propagation + Send + 'static,
+) -> Result<Revertible<impl Fn() + Send + Sync + Clone + 'static>, SlotsExhausted> {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    {
+        let mut buses = buses().lock().unwrap_or_else(|err| err.into_inner());
+        let bus = match buses.entry(identifier) {
+            MapEntry::Occupied(entry) => entry.into_mut(),
+            MapEntry::Vacant(entry) => entry.insert(Box::new(Bus::<T>::new(identifier)?)),
+        }
+        .downcast_mut::<Bus<T>>()
+        .expect("event identifier reused with a different payload type");
+        bus.insert(id, priority, Box::new(handler));
+    }
+
+    let revert = move || {
+        let mut buses = buses().lock().unwrap_or_else(|err| err.into_inner());
+        let should_remove = match buses.get_mut(identifier).and_then(|bus| bus.downcast_mut::<Bus<T>>()) {
+            Some(bus) => {
+                bus.handlers.retain(|entry| entry.id != id);
+                if bus.handlers.is_empty() {
+                    if let Some(teardown) = bus.teardown.take() {
+                        teardown();
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            None => false,
+        };
+        if should_remove {
+            buses.remove(identifier);
+        }
+    };
+    Ok(revert.into())
+}
+
+impl<T: 'static> Event<T> {
+    /// Subscribes a priority-ordered, cancelable handler to this event.
+    ///
+    /// See [`event_subscribe_prioritized`] for details and errors.
+    #[inline]
+    pub fn subscribe_prioritized(
+        &self,
+        priority: i32,
+        handler: impl FnMut(Option<&T>) -> Propagation + Send + 'static,
+    ) -> Result<Revertible<impl Fn() + Send + Sync + Clone + 'static>, SlotsExhausted> {
+        event_subscribe_prioritized(self.identifier, priority, handler)
+    }
+}
@@ -16,7 +16,13 @@
 //! ADDON_LOADED.subscribe(callback);
 //! ```
 
+mod bus;
 mod nexus;
+mod scope;
+mod trampoline;
+
+#[cfg(feature = "async")]
+pub mod r#async;
 
 #[cfg(feature = "arc")]
 pub mod arc;
@@ -35,7 +41,7 @@ use std::{
     mem,
 };
 
-pub use self::nexus::*;
+pub use self::{bus::*, nexus::*, scope::SubscriptionScope, trampoline::SlotsExhausted};
 
 /// An event identifier & payload type pair.
 #[derive(Debug, Clone, Copy)]
@@ -66,6 +72,19 @@ impl<T> Event<T> {
         unsafe { event_subscribe_typed(self.identifier, callback) }
     }
 
+    /// Subscribes to the event with a boxed, potentially stateful closure.
+    ///
+    /// Unlike [`subscribe`](Self::subscribe), the closure may capture and mutate state. See
+    /// [`event_subscribe_closure`] for details and limitations.
+    #[inline]
+    pub fn subscribe_fn(
+        &self,
+        callback: impl FnMut(Option<&T>) + Send + 'static,
+    ) -> Result<Revertible<impl Fn() + Send + Sync + Clone + 'static>, trampoline::SlotsExhausted>
+    {
+        unsafe { event_subscribe_closure(self.identifier, callback) }
+    }
+
     /// Raises the event.
     #[inline]
     pub fn raise(&self, event_data: &T) {
@@ -73,6 +92,40 @@ impl<T> Event<T> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<T: Clone + Send + 'static> Event<T> {
+    /// Waits for the next raise of the event and returns its payload.
+    ///
+    /// Subscribes for the duration of the returned future; dropping it before it resolves
+    /// unsubscribes again. The future only makes progress while [`r#async::pump`] is being
+    /// called regularly.
+    ///
+    /// # Errors
+    /// Returns [`trampoline::SlotsExhausted`] if every closure slot is currently in use.
+    #[inline]
+    pub fn recv(
+        &self,
+    ) -> Result<r#async::EventRecv<T, impl Fn() + Send + Sync + Clone + 'static>, trampoline::SlotsExhausted>
+    {
+        r#async::new_recv(self)
+    }
+
+    /// Returns a [`Stream`](futures::Stream) yielding the event's payload on every raise.
+    ///
+    /// Subscribes for the lifetime of the stream; dropping it unsubscribes. The stream only
+    /// makes progress while [`r#async::pump`] is being called regularly.
+    ///
+    /// # Errors
+    /// Returns [`trampoline::SlotsExhausted`] if every closure slot is currently in use.
+    #[inline]
+    pub fn stream(
+        &self,
+    ) -> Result<r#async::EventStream<T, impl Fn() + Send + Sync + Clone + 'static>, trampoline::SlotsExhausted>
+    {
+        r#async::new_stream(self)
+    }
+}
+
 pub type RawEventConsume<T> = extern "C-unwind" fn(event_args: *const T);
 
 pub type RawEventConsumeUnknown = RawEventConsume<c_void>;
@@ -129,6 +182,41 @@ pub unsafe fn event_subscribe_typed<T>(
     event_subscribe_unknown(identifier, callback)
 }
 
+/// Subscribes to an event with a boxed, potentially stateful closure.
+///
+/// The Nexus subscribe FFI only accepts a bare function pointer with no user-data parameter, so
+/// the closure cannot be passed to the host directly. Instead it is boxed and stored in a fixed
+/// bank of trampoline slots (see the private `trampoline` module), and a trampoline function
+/// pointer hard-coding the claimed slot's index is registered with the host in its place.
+///
+/// Returns a [`Revertible`] that unsubscribes the callback and frees its slot.
+///
+/// # Errors
+/// Returns [`trampoline::SlotsExhausted`] if every closure slot is currently in use.
+///
+/// # Safety
+/// See [`event_subscribe_typed`].
+pub unsafe fn event_subscribe_closure<T>(
+    identifier: impl AsRef<str>,
+    mut callback: impl FnMut(Option<&T>) + Send + 'static,
+) -> Result<Revertible<impl Fn() + Send + Sync + Clone + 'static>, trampoline::SlotsExhausted> {
+    let wrapped = move |data: *const c_void| callback(unsafe { data.cast::<T>().as_ref() });
+    let (index, callback) = trampoline::claim(Box::new(wrapped))?;
+
+    let identifier = str_to_c(identifier, "failed to convert event identifier");
+    let EventApi {
+        subscribe,
+        unsubscribe,
+        ..
+    } = AddonApi::get().event;
+    unsafe { subscribe(identifier.as_ptr(), callback) };
+    let revert = move || {
+        unsafe { unsubscribe(identifier.as_ptr(), callback) };
+        trampoline::free(index);
+    };
+    Ok(revert.into())
+}
+
 /// Unsubscribes a previously registered raw event callback.
 pub fn event_unsubscribe(identifier: impl AsRef<str>, callback: RawEventConsumeUnknown) {
     let identifier = str_to_c(identifier, "failed to convert event identifier");
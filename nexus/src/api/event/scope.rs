@@ -0,0 +1,94 @@
+//! Scoped subscription manager with RAII auto-unsubscribe.
+//!
+//! Building on the [`Revertible`](crate::revertible::Revertible) returned from `subscribe`,
+//! [`SubscriptionScope`] aggregates many subscriptions and reverts all of them together, either
+//! explicitly or when the scope itself is dropped, e.g. on addon unload or when a GUI window
+//! owning the scope closes.
+
+use super::{trampoline::SlotsExhausted, Event};
+use std::fmt;
+
+/// Aggregates event subscriptions and reverts all of them when dropped.
+///
+/// Build one with [`SubscriptionScope::new`] and register subscriptions through [`on`](Self::on):
+/// ```no_run
+/// # use nexus::event::{Event, SlotsExhausted, SubscriptionScope};
+/// # fn setup(some_event: &Event<i32>, other_event: &Event<i32>) -> Result<(), SlotsExhausted> {
+/// let mut scope = SubscriptionScope::new();
+/// scope
+///     .on(some_event, |payload| { let _ = payload; })?
+///     .on(other_event, |payload| { let _ = payload; })?;
+/// # Ok(())
+/// # }
+/// ```
+/// Dropping the scope reverts every subscription registered through it, in reverse order. Use
+/// [`take`](Self::take) or [`leak`](Self::leak) to deliberately detach subscriptions that should
+/// outlive the scope.
+#[derive(Default)]
+pub struct SubscriptionScope {
+    subscriptions: Vec<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl SubscriptionScope {
+    /// Creates an empty scope.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribes a closure to an event and registers it with this scope.
+    ///
+    /// The closure may capture and mutate state, see
+    /// [`Event::subscribe_fn`](super::Event::subscribe_fn), which backs this method.
+    ///
+    /// # Errors
+    /// Returns [`SlotsExhausted`] if every closure slot is currently in use.
+    pub fn on<T: 'static>(
+        &mut self,
+        event: &Event<T>,
+        handler: impl FnMut(Option<&T>) + Send + 'static,
+    ) -> Result<&mut Self, SlotsExhausted> {
+        let subscription = event.subscribe_fn(handler)?;
+        self.subscriptions
+            .push(Box::new(move || subscription.revert()));
+        Ok(self)
+    }
+
+    /// Reverts every subscription currently registered with this scope, leaving it empty.
+    pub fn clear(&mut self) {
+        for revert in self.subscriptions.drain(..).rev() {
+            revert();
+        }
+    }
+
+    /// Moves every subscription currently in this scope into a newly returned scope, leaving
+    /// this one empty.
+    ///
+    /// Useful to detach subscriptions that should outlive this scope, e.g. to hand them off to
+    /// longer-lived storage elsewhere.
+    pub fn take(&mut self) -> Self {
+        Self {
+            subscriptions: self.subscriptions.drain(..).collect(),
+        }
+    }
+
+    /// Consumes the scope without reverting any of its subscriptions, leaving them active
+    /// indefinitely.
+    pub fn leak(mut self) {
+        self.subscriptions.clear();
+    }
+}
+
+impl Drop for SubscriptionScope {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl fmt::Debug for SubscriptionScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubscriptionScope")
+            .field("subscriptions", &self.subscriptions.len())
+            .finish()
+    }
+}
@@ -0,0 +1,1137 @@
+//! Fixed bank of monomorphized trampoline functions for stateful event closures.
+//!
+//! The Nexus event API only exchanges a bare `extern "C-unwind" fn(*const c_void)` with no
+//! user-data parameter, so a capturing closure has nowhere to stash its state once it is handed
+//! to the host. Instead of requiring a context pointer we keep a fixed-size bank of distinct
+//! trampoline functions, each hard-coding its own slot index, and store the boxed closures in a
+//! global table keyed by that same index. Subscribing claims a free slot and hands the host that
+//! slot's trampoline pointer; the trampoline looks its closure up by index and invokes it.
+
+use super::RawEventConsumeUnknown;
+use std::{
+    error::Error,
+    ffi::c_void,
+    fmt, mem,
+    sync::Mutex,
+};
+
+/// Number of available closure slots.
+///
+/// Each concurrently active [`event_subscribe_closure`](super::event_subscribe_closure)
+/// subscription occupies one slot; subscribing beyond this limit fails with [`SlotsExhausted`]
+/// instead of panicking.
+pub const SLOT_COUNT: usize = 1024;
+
+/// State of a single trampoline slot.
+///
+/// [`Slot::Invoking`] is a sentinel the trampoline leaves behind while the closure is running:
+/// the global lock is released for the duration of the call (so a closure that subscribes,
+/// unsubscribes, or raises an event reentrantly does not deadlock on it), and the trampoline
+/// decides whether to restore the closure afterwards by checking it is still there.
+enum Slot {
+    Vacant,
+    Occupied(Box<dyn FnMut(*const c_void) + Send>),
+    Invoking,
+}
+
+impl Slot {
+    fn is_vacant(&self) -> bool {
+        matches!(self, Slot::Vacant)
+    }
+}
+
+static SLOTS: Mutex<[Slot; SLOT_COUNT]> = Mutex::new([const { Slot::Vacant }; SLOT_COUNT]);
+
+/// Returned when every closure slot is currently in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotsExhausted;
+
+impl fmt::Display for SlotsExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no free event closure slots left (limit is {SLOT_COUNT})")
+    }
+}
+
+impl Error for SlotsExhausted {}
+
+/// Claims a free slot for the given closure.
+///
+/// Returns the claimed slot's index together with its trampoline function pointer, which must be
+/// passed to the host's subscribe call.
+pub(crate) fn claim(
+    closure: Box<dyn FnMut(*const c_void) + Send>,
+) -> Result<(usize, RawEventConsumeUnknown), SlotsExhausted> {
+    let mut slots = SLOTS.lock().unwrap_or_else(|err| err.into_inner());
+    let index = slots.iter().position(Slot::is_vacant).ok_or(SlotsExhausted)?;
+    slots[index] = Slot::Occupied(closure);
+    Ok((index, TRAMPOLINES[index]))
+}
+
+/// Frees a previously claimed slot, dropping its closure.
+pub(crate) fn free(index: usize) {
+    let mut slots = SLOTS.lock().unwrap_or_else(|err| err.into_inner());
+    slots[index] = Slot::Vacant;
+}
+
+macro_rules! define_trampolines {
+    ( $( $name:ident => $index:literal ),* $(,)? ) => {
+        $(
+            extern "C-unwind" fn $name(data: *const c_void) {
+                // Take the closure out from behind the lock and release the lock again before
+                // calling it, so a closure that re-enters the event system (subscribing,
+                // unsubscribing, or raising) does not deadlock on `SLOTS`.
+                let mut closure = {
+                    let mut slots = SLOTS.lock().unwrap_or_else(|err| err.into_inner());
+                    match mem::replace(&mut slots[$index], Slot::Invoking) {
+                        Slot::Occupied(closure) => closure,
+                        other => {
+                            slots[$index] = other;
+                            return;
+                        }
+                    }
+                };
+
+                closure(data);
+
+                // Only restore the closure if the slot is still marked as invoking: if it was
+                // freed (or even reclaimed by a new subscription) while the closure above ran,
+                // leave that in place instead of clobbering it.
+                let mut slots = SLOTS.lock().unwrap_or_else(|err| err.into_inner());
+                if let Slot::Invoking = slots[$index] {
+                    slots[$index] = Slot::Occupied(closure);
+                }
+            }
+        )*
+
+        static TRAMPOLINES: [RawEventConsumeUnknown; SLOT_COUNT] = [
+            $( $name as RawEventConsumeUnknown ),*
+        ];
+    };
+}
+
+define_trampolines! {
+    slot_0 => 0,
+    slot_1 => 1,
+    slot_2 => 2,
+    slot_3 => 3,
+    slot_4 => 4,
+    slot_5 => 5,
+    slot_6 => 6,
+    slot_7 => 7,
+    slot_8 => 8,
+    slot_9 => 9,
+    slot_10 => 10,
+    slot_11 => 11,
+    slot_12 => 12,
+    slot_13 => 13,
+    slot_14 => 14,
+    slot_15 => 15,
+    slot_16 => 16,
+    slot_17 => 17,
+    slot_18 => 18,
+    slot_19 => 19,
+    slot_20 => 20,
+    slot_21 => 21,
+    slot_22 => 22,
+    slot_23 => 23,
+    slot_24 => 24,
+    slot_25 => 25,
+    slot_26 => 26,
+    slot_27 => 27,
+    slot_28 => 28,
+    slot_29 => 29,
+    slot_30 => 30,
+    slot_31 => 31,
+    slot_32 => 32,
+    slot_33 => 33,
+    slot_34 => 34,
+    slot_35 => 35,
+    slot_36 => 36,
+    slot_37 => 37,
+    slot_38 => 38,
+    slot_39 => 39,
+    slot_40 => 40,
+    slot_41 => 41,
+    slot_42 => 42,
+    slot_43 => 43,
+    slot_44 => 44,
+    slot_45 => 45,
+    slot_46 => 46,
+    slot_47 => 47,
+    slot_48 => 48,
+    slot_49 => 49,
+    slot_50 => 50,
+    slot_51 => 51,
+    slot_52 => 52,
+    slot_53 => 53,
+    slot_54 => 54,
+    slot_55 => 55,
+    slot_56 => 56,
+    slot_57 => 57,
+    slot_58 => 58,
+    slot_59 => 59,
+    slot_60 => 60,
+    slot_61 => 61,
+    slot_62 => 62,
+    slot_63 => 63,
+    slot_64 => 64,
+    slot_65 => 65,
+    slot_66 => 66,
+    slot_67 => 67,
+    slot_68 => 68,
+    slot_69 => 69,
+    slot_70 => 70,
+    slot_71 => 71,
+    slot_72 => 72,
+    slot_73 => 73,
+    slot_74 => 74,
+    slot_75 => 75,
+    slot_76 => 76,
+    slot_77 => 77,
+    slot_78 => 78,
+    slot_79 => 79,
+    slot_80 => 80,
+    slot_81 => 81,
+    slot_82 => 82,
+    slot_83 => 83,
+    slot_84 => 84,
+    slot_85 => 85,
+    slot_86 => 86,
+    slot_87 => 87,
+    slot_88 => 88,
+    slot_89 => 89,
+    slot_90 => 90,
+    slot_91 => 91,
+    slot_92 => 92,
+    slot_93 => 93,
+    slot_94 => 94,
+    slot_95 => 95,
+    slot_96 => 96,
+    slot_97 => 97,
+    slot_98 => 98,
+    slot_99 => 99,
+    slot_100 => 100,
+    slot_101 => 101,
+    slot_102 => 102,
+    slot_103 => 103,
+    slot_104 => 104,
+    slot_105 => 105,
+    slot_106 => 106,
+    slot_107 => 107,
+    slot_108 => 108,
+    slot_109 => 109,
+    slot_110 => 110,
+    slot_111 => 111,
+    slot_112 => 112,
+    slot_113 => 113,
+    slot_114 => 114,
+    slot_115 => 115,
+    slot_116 => 116,
+    slot_117 => 117,
+    slot_118 => 118,
+    slot_119 => 119,
+    slot_120 => 120,
+    slot_121 => 121,
+    slot_122 => 122,
+    slot_123 => 123,
+    slot_124 => 124,
+    slot_125 => 125,
+    slot_126 => 126,
+    slot_127 => 127,
+    slot_128 => 128,
+    slot_129 => 129,
+    slot_130 => 130,
+    slot_131 => 131,
+    slot_132 => 132,
+    slot_133 => 133,
+    slot_134 => 134,
+    slot_135 => 135,
+    slot_136 => 136,
+    slot_137 => 137,
+    slot_138 => 138,
+    slot_139 => 139,
+    slot_140 => 140,
+    slot_141 => 141,
+    slot_142 => 142,
+    slot_143 => 143,
+    slot_144 => 144,
+    slot_145 => 145,
+    slot_146 => 146,
+    slot_147 => 147,
+    slot_148 => 148,
+    slot_149 => 149,
+    slot_150 => 150,
+    slot_151 => 151,
+    slot_152 => 152,
+    slot_153 => 153,
+    slot_154 => 154,
+    slot_155 => 155,
+    slot_156 => 156,
+    slot_157 => 157,
+    slot_158 => 158,
+    slot_159 => 159,
+    slot_160 => 160,
+    slot_161 => 161,
+    slot_162 => 162,
+    slot_163 => 163,
+    slot_164 => 164,
+    slot_165 => 165,
+    slot_166 => 166,
+    slot_167 => 167,
+    slot_168 => 168,
+    slot_169 => 169,
+    slot_170 => 170,
+    slot_171 => 171,
+    slot_172 => 172,
+    slot_173 => 173,
+    slot_174 => 174,
+    slot_175 => 175,
+    slot_176 => 176,
+    slot_177 => 177,
+    slot_178 => 178,
+    slot_179 => 179,
+    slot_180 => 180,
+    slot_181 => 181,
+    slot_182 => 182,
+    slot_183 => 183,
+    slot_184 => 184,
+    slot_185 => 185,
+    slot_186 => 186,
+    slot_187 => 187,
+    slot_188 => 188,
+    slot_189 => 189,
+    slot_190 => 190,
+    slot_191 => 191,
+    slot_192 => 192,
+    slot_193 => 193,
+    slot_194 => 194,
+    slot_195 => 195,
+    slot_196 => 196,
+    slot_197 => 197,
+    slot_198 => 198,
+    slot_199 => 199,
+    slot_200 => 200,
+    slot_201 => 201,
+    slot_202 => 202,
+    slot_203 => 203,
+    slot_204 => 204,
+    slot_205 => 205,
+    slot_206 => 206,
+    slot_207 => 207,
+    slot_208 => 208,
+    slot_209 => 209,
+    slot_210 => 210,
+    slot_211 => 211,
+    slot_212 => 212,
+    slot_213 => 213,
+    slot_214 => 214,
+    slot_215 => 215,
+    slot_216 => 216,
+    slot_217 => 217,
+    slot_218 => 218,
+    slot_219 => 219,
+    slot_220 => 220,
+    slot_221 => 221,
+    slot_222 => 222,
+    slot_223 => 223,
+    slot_224 => 224,
+    slot_225 => 225,
+    slot_226 => 226,
+    slot_227 => 227,
+    slot_228 => 228,
+    slot_229 => 229,
+    slot_230 => 230,
+    slot_231 => 231,
+    slot_232 => 232,
+    slot_233 => 233,
+    slot_234 => 234,
+    slot_235 => 235,
+    slot_236 => 236,
+    slot_237 => 237,
+    slot_238 => 238,
+    slot_239 => 239,
+    slot_240 => 240,
+    slot_241 => 241,
+    slot_242 => 242,
+    slot_243 => 243,
+    slot_244 => 244,
+    slot_245 => 245,
+    slot_246 => 246,
+    slot_247 => 247,
+    slot_248 => 248,
+    slot_249 => 249,
+    slot_250 => 250,
+    slot_251 => 251,
+    slot_252 => 252,
+    slot_253 => 253,
+    slot_254 => 254,
+    slot_255 => 255,
+    slot_256 => 256,
+    slot_257 => 257,
+    slot_258 => 258,
+    slot_259 => 259,
+    slot_260 => 260,
+    slot_261 => 261,
+    slot_262 => 262,
+    slot_263 => 263,
+    slot_264 => 264,
+    slot_265 => 265,
+    slot_266 => 266,
+    slot_267 => 267,
+    slot_268 => 268,
+    slot_269 => 269,
+    slot_270 => 270,
+    slot_271 => 271,
+    slot_272 => 272,
+    slot_273 => 273,
+    slot_274 => 274,
+    slot_275 => 275,
+    slot_276 => 276,
+    slot_277 => 277,
+    slot_278 => 278,
+    slot_279 => 279,
+    slot_280 => 280,
+    slot_281 => 281,
+    slot_282 => 282,
+    slot_283 => 283,
+    slot_284 => 284,
+    slot_285 => 285,
+    slot_286 => 286,
+    slot_287 => 287,
+    slot_288 => 288,
+    slot_289 => 289,
+    slot_290 => 290,
+    slot_291 => 291,
+    slot_292 => 292,
+    slot_293 => 293,
+    slot_294 => 294,
+    slot_295 => 295,
+    slot_296 => 296,
+    slot_297 => 297,
+    slot_298 => 298,
+    slot_299 => 299,
+    slot_300 => 300,
+    slot_301 => 301,
+    slot_302 => 302,
+    slot_303 => 303,
+    slot_304 => 304,
+    slot_305 => 305,
+    slot_306 => 306,
+    slot_307 => 307,
+    slot_308 => 308,
+    slot_309 => 309,
+    slot_310 => 310,
+    slot_311 => 311,
+    slot_312 => 312,
+    slot_313 => 313,
+    slot_314 => 314,
+    slot_315 => 315,
+    slot_316 => 316,
+    slot_317 => 317,
+    slot_318 => 318,
+    slot_319 => 319,
+    slot_320 => 320,
+    slot_321 => 321,
+    slot_322 => 322,
+    slot_323 => 323,
+    slot_324 => 324,
+    slot_325 => 325,
+    slot_326 => 326,
+    slot_327 => 327,
+    slot_328 => 328,
+    slot_329 => 329,
+    slot_330 => 330,
+    slot_331 => 331,
+    slot_332 => 332,
+    slot_333 => 333,
+    slot_334 => 334,
+    slot_335 => 335,
+    slot_336 => 336,
+    slot_337 => 337,
+    slot_338 => 338,
+    slot_339 => 339,
+    slot_340 => 340,
+    slot_341 => 341,
+    slot_342 => 342,
+    slot_343 => 343,
+    slot_344 => 344,
+    slot_345 => 345,
+    slot_346 => 346,
+    slot_347 => 347,
+    slot_348 => 348,
+    slot_349 => 349,
+    slot_350 => 350,
+    slot_351 => 351,
+    slot_352 => 352,
+    slot_353 => 353,
+    slot_354 => 354,
+    slot_355 => 355,
+    slot_356 => 356,
+    slot_357 => 357,
+    slot_358 => 358,
+    slot_359 => 359,
+    slot_360 => 360,
+    slot_361 => 361,
+    slot_362 => 362,
+    slot_363 => 363,
+    slot_364 => 364,
+    slot_365 => 365,
+    slot_366 => 366,
+    slot_367 => 367,
+    slot_368 => 368,
+    slot_369 => 369,
+    slot_370 => 370,
+    slot_371 => 371,
+    slot_372 => 372,
+    slot_373 => 373,
+    slot_374 => 374,
+    slot_375 => 375,
+    slot_376 => 376,
+    slot_377 => 377,
+    slot_378 => 378,
+    slot_379 => 379,
+    slot_380 => 380,
+    slot_381 => 381,
+    slot_382 => 382,
+    slot_383 => 383,
+    slot_384 => 384,
+    slot_385 => 385,
+    slot_386 => 386,
+    slot_387 => 387,
+    slot_388 => 388,
+    slot_389 => 389,
+    slot_390 => 390,
+    slot_391 => 391,
+    slot_392 => 392,
+    slot_393 => 393,
+    slot_394 => 394,
+    slot_395 => 395,
+    slot_396 => 396,
+    slot_397 => 397,
+    slot_398 => 398,
+    slot_399 => 399,
+    slot_400 => 400,
+    slot_401 => 401,
+    slot_402 => 402,
+    slot_403 => 403,
+    slot_404 => 404,
+    slot_405 => 405,
+    slot_406 => 406,
+    slot_407 => 407,
+    slot_408 => 408,
+    slot_409 => 409,
+    slot_410 => 410,
+    slot_411 => 411,
+    slot_412 => 412,
+    slot_413 => 413,
+    slot_414 => 414,
+    slot_415 => 415,
+    slot_416 => 416,
+    slot_417 => 417,
+    slot_418 => 418,
+    slot_419 => 419,
+    slot_420 => 420,
+    slot_421 => 421,
+    slot_422 => 422,
+    slot_423 => 423,
+    slot_424 => 424,
+    slot_425 => 425,
+    slot_426 => 426,
+    slot_427 => 427,
+    slot_428 => 428,
+    slot_429 => 429,
+    slot_430 => 430,
+    slot_431 => 431,
+    slot_432 => 432,
+    slot_433 => 433,
+    slot_434 => 434,
+    slot_435 => 435,
+    slot_436 => 436,
+    slot_437 => 437,
+    slot_438 => 438,
+    slot_439 => 439,
+    slot_440 => 440,
+    slot_441 => 441,
+    slot_442 => 442,
+    slot_443 => 443,
+    slot_444 => 444,
+    slot_445 => 445,
+    slot_446 => 446,
+    slot_447 => 447,
+    slot_448 => 448,
+    slot_449 => 449,
+    slot_450 => 450,
+    slot_451 => 451,
+    slot_452 => 452,
+    slot_453 => 453,
+    slot_454 => 454,
+    slot_455 => 455,
+    slot_456 => 456,
+    slot_457 => 457,
+    slot_458 => 458,
+    slot_459 => 459,
+    slot_460 => 460,
+    slot_461 => 461,
+    slot_462 => 462,
+    slot_463 => 463,
+    slot_464 => 464,
+    slot_465 => 465,
+    slot_466 => 466,
+    slot_467 => 467,
+    slot_468 => 468,
+    slot_469 => 469,
+    slot_470 => 470,
+    slot_471 => 471,
+    slot_472 => 472,
+    slot_473 => 473,
+    slot_474 => 474,
+    slot_475 => 475,
+    slot_476 => 476,
+    slot_477 => 477,
+    slot_478 => 478,
+    slot_479 => 479,
+    slot_480 => 480,
+    slot_481 => 481,
+    slot_482 => 482,
+    slot_483 => 483,
+    slot_484 => 484,
+    slot_485 => 485,
+    slot_486 => 486,
+    slot_487 => 487,
+    slot_488 => 488,
+    slot_489 => 489,
+    slot_490 => 490,
+    slot_491 => 491,
+    slot_492 => 492,
+    slot_493 => 493,
+    slot_494 => 494,
+    slot_495 => 495,
+    slot_496 => 496,
+    slot_497 => 497,
+    slot_498 => 498,
+    slot_499 => 499,
+    slot_500 => 500,
+    slot_501 => 501,
+    slot_502 => 502,
+    slot_503 => 503,
+    slot_504 => 504,
+    slot_505 => 505,
+    slot_506 => 506,
+    slot_507 => 507,
+    slot_508 => 508,
+    slot_509 => 509,
+    slot_510 => 510,
+    slot_511 => 511,
+    slot_512 => 512,
+    slot_513 => 513,
+    slot_514 => 514,
+    slot_515 => 515,
+    slot_516 => 516,
+    slot_517 => 517,
+    slot_518 => 518,
+    slot_519 => 519,
+    slot_520 => 520,
+    slot_521 => 521,
+    slot_522 => 522,
+    slot_523 => 523,
+    slot_524 => 524,
+    slot_525 => 525,
+    slot_526 => 526,
+    slot_527 => 527,
+    slot_528 => 528,
+    slot_529 => 529,
+    slot_530 => 530,
+    slot_531 => 531,
+    slot_532 => 532,
+    slot_533 => 533,
+    slot_534 => 534,
+    slot_535 => 535,
+    slot_536 => 536,
+    slot_537 => 537,
+    slot_538 => 538,
+    slot_539 => 539,
+    slot_540 => 540,
+    slot_541 => 541,
+    slot_542 => 542,
+    slot_543 => 543,
+    slot_544 => 544,
+    slot_545 => 545,
+    slot_546 => 546,
+    slot_547 => 547,
+    slot_548 => 548,
+    slot_549 => 549,
+    slot_550 => 550,
+    slot_551 => 551,
+    slot_552 => 552,
+    slot_553 => 553,
+    slot_554 => 554,
+    slot_555 => 555,
+    slot_556 => 556,
+    slot_557 => 557,
+    slot_558 => 558,
+    slot_559 => 559,
+    slot_560 => 560,
+    slot_561 => 561,
+    slot_562 => 562,
+    slot_563 => 563,
+    slot_564 => 564,
+    slot_565 => 565,
+    slot_566 => 566,
+    slot_567 => 567,
+    slot_568 => 568,
+    slot_569 => 569,
+    slot_570 => 570,
+    slot_571 => 571,
+    slot_572 => 572,
+    slot_573 => 573,
+    slot_574 => 574,
+    slot_575 => 575,
+    slot_576 => 576,
+    slot_577 => 577,
+    slot_578 => 578,
+    slot_579 => 579,
+    slot_580 => 580,
+    slot_581 => 581,
+    slot_582 => 582,
+    slot_583 => 583,
+    slot_584 => 584,
+    slot_585 => 585,
+    slot_586 => 586,
+    slot_587 => 587,
+    slot_588 => 588,
+    slot_589 => 589,
+    slot_590 => 590,
+    slot_591 => 591,
+    slot_592 => 592,
+    slot_593 => 593,
+    slot_594 => 594,
+    slot_595 => 595,
+    slot_596 => 596,
+    slot_597 => 597,
+    slot_598 => 598,
+    slot_599 => 599,
+    slot_600 => 600,
+    slot_601 => 601,
+    slot_602 => 602,
+    slot_603 => 603,
+    slot_604 => 604,
+    slot_605 => 605,
+    slot_606 => 606,
+    slot_607 => 607,
+    slot_608 => 608,
+    slot_609 => 609,
+    slot_610 => 610,
+    slot_611 => 611,
+    slot_612 => 612,
+    slot_613 => 613,
+    slot_614 => 614,
+    slot_615 => 615,
+    slot_616 => 616,
+    slot_617 => 617,
+    slot_618 => 618,
+    slot_619 => 619,
+    slot_620 => 620,
+    slot_621 => 621,
+    slot_622 => 622,
+    slot_623 => 623,
+    slot_624 => 624,
+    slot_625 => 625,
+    slot_626 => 626,
+    slot_627 => 627,
+    slot_628 => 628,
+    slot_629 => 629,
+    slot_630 => 630,
+    slot_631 => 631,
+    slot_632 => 632,
+    slot_633 => 633,
+    slot_634 => 634,
+    slot_635 => 635,
+    slot_636 => 636,
+    slot_637 => 637,
+    slot_638 => 638,
+    slot_639 => 639,
+    slot_640 => 640,
+    slot_641 => 641,
+    slot_642 => 642,
+    slot_643 => 643,
+    slot_644 => 644,
+    slot_645 => 645,
+    slot_646 => 646,
+    slot_647 => 647,
+    slot_648 => 648,
+    slot_649 => 649,
+    slot_650 => 650,
+    slot_651 => 651,
+    slot_652 => 652,
+    slot_653 => 653,
+    slot_654 => 654,
+    slot_655 => 655,
+    slot_656 => 656,
+    slot_657 => 657,
+    slot_658 => 658,
+    slot_659 => 659,
+    slot_660 => 660,
+    slot_661 => 661,
+    slot_662 => 662,
+    slot_663 => 663,
+    slot_664 => 664,
+    slot_665 => 665,
+    slot_666 => 666,
+    slot_667 => 667,
+    slot_668 => 668,
+    slot_669 => 669,
+    slot_670 => 670,
+    slot_671 => 671,
+    slot_672 => 672,
+    slot_673 => 673,
+    slot_674 => 674,
+    slot_675 => 675,
+    slot_676 => 676,
+    slot_677 => 677,
+    slot_678 => 678,
+    slot_679 => 679,
+    slot_680 => 680,
+    slot_681 => 681,
+    slot_682 => 682,
+    slot_683 => 683,
+    slot_684 => 684,
+    slot_685 => 685,
+    slot_686 => 686,
+    slot_687 => 687,
+    slot_688 => 688,
+    slot_689 => 689,
+    slot_690 => 690,
+    slot_691 => 691,
+    slot_692 => 692,
+    slot_693 => 693,
+    slot_694 => 694,
+    slot_695 => 695,
+    slot_696 => 696,
+    slot_697 => 697,
+    slot_698 => 698,
+    slot_699 => 699,
+    slot_700 => 700,
+    slot_701 => 701,
+    slot_702 => 702,
+    slot_703 => 703,
+    slot_704 => 704,
+    slot_705 => 705,
+    slot_706 => 706,
+    slot_707 => 707,
+    slot_708 => 708,
+    slot_709 => 709,
+    slot_710 => 710,
+    slot_711 => 711,
+    slot_712 => 712,
+    slot_713 => 713,
+    slot_714 => 714,
+    slot_715 => 715,
+    slot_716 => 716,
+    slot_717 => 717,
+    slot_718 => 718,
+    slot_719 => 719,
+    slot_720 => 720,
+    slot_721 => 721,
+    slot_722 => 722,
+    slot_723 => 723,
+    slot_724 => 724,
+    slot_725 => 725,
+    slot_726 => 726,
+    slot_727 => 727,
+    slot_728 => 728,
+    slot_729 => 729,
+    slot_730 => 730,
+    slot_731 => 731,
+    slot_732 => 732,
+    slot_733 => 733,
+    slot_734 => 734,
+    slot_735 => 735,
+    slot_736 => 736,
+    slot_737 => 737,
+    slot_738 => 738,
+    slot_739 => 739,
+    slot_740 => 740,
+    slot_741 => 741,
+    slot_742 => 742,
+    slot_743 => 743,
+    slot_744 => 744,
+    slot_745 => 745,
+    slot_746 => 746,
+    slot_747 => 747,
+    slot_748 => 748,
+    slot_749 => 749,
+    slot_750 => 750,
+    slot_751 => 751,
+    slot_752 => 752,
+    slot_753 => 753,
+    slot_754 => 754,
+    slot_755 => 755,
+    slot_756 => 756,
+    slot_757 => 757,
+    slot_758 => 758,
+    slot_759 => 759,
+    slot_760 => 760,
+    slot_761 => 761,
+    slot_762 => 762,
+    slot_763 => 763,
+    slot_764 => 764,
+    slot_765 => 765,
+    slot_766 => 766,
+    slot_767 => 767,
+    slot_768 => 768,
+    slot_769 => 769,
+    slot_770 => 770,
+    slot_771 => 771,
+    slot_772 => 772,
+    slot_773 => 773,
+    slot_774 => 774,
+    slot_775 => 775,
+    slot_776 => 776,
+    slot_777 => 777,
+    slot_778 => 778,
+    slot_779 => 779,
+    slot_780 => 780,
+    slot_781 => 781,
+    slot_782 => 782,
+    slot_783 => 783,
+    slot_784 => 784,
+    slot_785 => 785,
+    slot_786 => 786,
+    slot_787 => 787,
+    slot_788 => 788,
+    slot_789 => 789,
+    slot_790 => 790,
+    slot_791 => 791,
+    slot_792 => 792,
+    slot_793 => 793,
+    slot_794 => 794,
+    slot_795 => 795,
+    slot_796 => 796,
+    slot_797 => 797,
+    slot_798 => 798,
+    slot_799 => 799,
+    slot_800 => 800,
+    slot_801 => 801,
+    slot_802 => 802,
+    slot_803 => 803,
+    slot_804 => 804,
+    slot_805 => 805,
+    slot_806 => 806,
+    slot_807 => 807,
+    slot_808 => 808,
+    slot_809 => 809,
+    slot_810 => 810,
+    slot_811 => 811,
+    slot_812 => 812,
+    slot_813 => 813,
+    slot_814 => 814,
+    slot_815 => 815,
+    slot_816 => 816,
+    slot_817 => 817,
+    slot_818 => 818,
+    slot_819 => 819,
+    slot_820 => 820,
+    slot_821 => 821,
+    slot_822 => 822,
+    slot_823 => 823,
+    slot_824 => 824,
+    slot_825 => 825,
+    slot_826 => 826,
+    slot_827 => 827,
+    slot_828 => 828,
+    slot_829 => 829,
+    slot_830 => 830,
+    slot_831 => 831,
+    slot_832 => 832,
+    slot_833 => 833,
+    slot_834 => 834,
+    slot_835 => 835,
+    slot_836 => 836,
+    slot_837 => 837,
+    slot_838 => 838,
+    slot_839 => 839,
+    slot_840 => 840,
+    slot_841 => 841,
+    slot_842 => 842,
+    slot_843 => 843,
+    slot_844 => 844,
+    slot_845 => 845,
+    slot_846 => 846,
+    slot_847 => 847,
+    slot_848 => 848,
+    slot_849 => 849,
+    slot_850 => 850,
+    slot_851 => 851,
+    slot_852 => 852,
+    slot_853 => 853,
+    slot_854 => 854,
+    slot_855 => 855,
+    slot_856 => 856,
+    slot_857 => 857,
+    slot_858 => 858,
+    slot_859 => 859,
+    slot_860 => 860,
+    slot_861 => 861,
+    slot_862 => 862,
+    slot_863 => 863,
+    slot_864 => 864,
+    slot_865 => 865,
+    slot_866 => 866,
+    slot_867 => 867,
+    slot_868 => 868,
+    slot_869 => 869,
+    slot_870 => 870,
+    slot_871 => 871,
+    slot_872 => 872,
+    slot_873 => 873,
+    slot_874 => 874,
+    slot_875 => 875,
+    slot_876 => 876,
+    slot_877 => 877,
+    slot_878 => 878,
+    slot_879 => 879,
+    slot_880 => 880,
+    slot_881 => 881,
+    slot_882 => 882,
+    slot_883 => 883,
+    slot_884 => 884,
+    slot_885 => 885,
+    slot_886 => 886,
+    slot_887 => 887,
+    slot_888 => 888,
+    slot_889 => 889,
+    slot_890 => 890,
+    slot_891 => 891,
+    slot_892 => 892,
+    slot_893 => 893,
+    slot_894 => 894,
+    slot_895 => 895,
+    slot_896 => 896,
+    slot_897 => 897,
+    slot_898 => 898,
+    slot_899 => 899,
+    slot_900 => 900,
+    slot_901 => 901,
+    slot_902 => 902,
+    slot_903 => 903,
+    slot_904 => 904,
+    slot_905 => 905,
+    slot_906 => 906,
+    slot_907 => 907,
+    slot_908 => 908,
+    slot_909 => 909,
+    slot_910 => 910,
+    slot_911 => 911,
+    slot_912 => 912,
+    slot_913 => 913,
+    slot_914 => 914,
+    slot_915 => 915,
+    slot_916 => 916,
+    slot_917 => 917,
+    slot_918 => 918,
+    slot_919 => 919,
+    slot_920 => 920,
+    slot_921 => 921,
+    slot_922 => 922,
+    slot_923 => 923,
+    slot_924 => 924,
+    slot_925 => 925,
+    slot_926 => 926,
+    slot_927 => 927,
+    slot_928 => 928,
+    slot_929 => 929,
+    slot_930 => 930,
+    slot_931 => 931,
+    slot_932 => 932,
+    slot_933 => 933,
+    slot_934 => 934,
+    slot_935 => 935,
+    slot_936 => 936,
+    slot_937 => 937,
+    slot_938 => 938,
+    slot_939 => 939,
+    slot_940 => 940,
+    slot_941 => 941,
+    slot_942 => 942,
+    slot_943 => 943,
+    slot_944 => 944,
+    slot_945 => 945,
+    slot_946 => 946,
+    slot_947 => 947,
+    slot_948 => 948,
+    slot_949 => 949,
+    slot_950 => 950,
+    slot_951 => 951,
+    slot_952 => 952,
+    slot_953 => 953,
+    slot_954 => 954,
+    slot_955 => 955,
+    slot_956 => 956,
+    slot_957 => 957,
+    slot_958 => 958,
+    slot_959 => 959,
+    slot_960 => 960,
+    slot_961 => 961,
+    slot_962 => 962,
+    slot_963 => 963,
+    slot_964 => 964,
+    slot_965 => 965,
+    slot_966 => 966,
+    slot_967 => 967,
+    slot_968 => 968,
+    slot_969 => 969,
+    slot_970 => 970,
+    slot_971 => 971,
+    slot_972 => 972,
+    slot_973 => 973,
+    slot_974 => 974,
+    slot_975 => 975,
+    slot_976 => 976,
+    slot_977 => 977,
+    slot_978 => 978,
+    slot_979 => 979,
+    slot_980 => 980,
+    slot_981 => 981,
+    slot_982 => 982,
+    slot_983 => 983,
+    slot_984 => 984,
+    slot_985 => 985,
+    slot_986 => 986,
+    slot_987 => 987,
+    slot_988 => 988,
+    slot_989 => 989,
+    slot_990 => 990,
+    slot_991 => 991,
+    slot_992 => 992,
+    slot_993 => 993,
+    slot_994 => 994,
+    slot_995 => 995,
+    slot_996 => 996,
+    slot_997 => 997,
+    slot_998 => 998,
+    slot_999 => 999,
+    slot_1000 => 1000,
+    slot_1001 => 1001,
+    slot_1002 => 1002,
+    slot_1003 => 1003,
+    slot_1004 => 1004,
+    slot_1005 => 1005,
+    slot_1006 => 1006,
+    slot_1007 => 1007,
+    slot_1008 => 1008,
+    slot_1009 => 1009,
+    slot_1010 => 1010,
+    slot_1011 => 1011,
+    slot_1012 => 1012,
+    slot_1013 => 1013,
+    slot_1014 => 1014,
+    slot_1015 => 1015,
+    slot_1016 => 1016,
+    slot_1017 => 1017,
+    slot_1018 => 1018,
+    slot_1019 => 1019,
+    slot_1020 => 1020,
+    slot_1021 => 1021,
+    slot_1022 => 1022,
+    slot_1023 => 1023,
+}